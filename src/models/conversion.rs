@@ -0,0 +1,231 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::utils::error::SimulationError;
+
+/// A typed value parsed out of a raw message payload. `Generator` validates
+/// its payload as one of these, and `ExclusiveGateway`/`Processor` parse incoming payloads into
+/// one of these, so routing and processing logic can branch on numbers and
+/// timestamps rather than string equality.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bytes(String),
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    Timestamp(DateTime<Utc>),
+}
+
+/// Describes how a raw string payload should be parsed into a typed
+/// [`Value`]. Resolvable via `FromStr` (e.g. `"int"`, `"float"`, `"bool"`,
+/// `"timestamp|%Y-%m-%d"`) so it can be specified directly in a model's YAML
+/// configuration.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Conversion {
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    Timestamp,
+    TimestampFmt(String),
+}
+
+impl Conversion {
+    const DEFAULT_TIMESTAMP_FMT: &'static str = "%Y-%m-%dT%H:%M:%S";
+
+    /// Parses `payload` according to this conversion.
+    pub fn convert(&self, payload: &str) -> Result<Value, SimulationError> {
+        match self {
+            Conversion::Bytes => Ok(Value::Bytes(payload.to_string())),
+            Conversion::Integer => payload
+                .parse::<i64>()
+                .map(Value::Integer)
+                .map_err(|err| SimulationError::ConversionError(err.to_string())),
+            Conversion::Float => payload
+                .parse::<f64>()
+                .map(Value::Float)
+                .map_err(|err| SimulationError::ConversionError(err.to_string())),
+            Conversion::Boolean => payload
+                .parse::<bool>()
+                .map(Value::Boolean)
+                .map_err(|err| SimulationError::ConversionError(err.to_string())),
+            Conversion::Timestamp => parse_timestamp(payload, Self::DEFAULT_TIMESTAMP_FMT),
+            Conversion::TimestampFmt(fmt) => parse_timestamp(payload, fmt),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    /// Renders a value as text that `Conversion::convert` parses back into
+    /// the same value. Timestamps are always rendered in the default
+    /// `timestamp` format, whatever format they were parsed with.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bytes(payload) => write!(f, "{}", payload),
+            Value::Integer(n) => write!(f, "{}", n),
+            Value::Float(n) => write!(f, "{}", n),
+            Value::Boolean(b) => write!(f, "{}", b),
+            Value::Timestamp(timestamp) => {
+                write!(f, "{}", timestamp.format(Conversion::DEFAULT_TIMESTAMP_FMT))
+            }
+        }
+    }
+}
+
+fn parse_timestamp(payload: &str, fmt: &str) -> Result<Value, SimulationError> {
+    // A date-only format such as `%Y-%m-%d` has no time fields, which
+    // `NaiveDateTime::parse_from_str` rejects; such a payload is read as
+    // midnight UTC of that date.
+    NaiveDateTime::parse_from_str(payload, fmt)
+        .or_else(|err| {
+            NaiveDate::parse_from_str(payload, fmt)
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .ok_or(err)
+        })
+        .map(|naive| Value::Timestamp(DateTime::from_utc(naive, Utc)))
+        .map_err(|err| SimulationError::ConversionError(err.to_string()))
+}
+
+impl FromStr for Conversion {
+    type Err = SimulationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('|') {
+            Some(("timestamp", fmt)) => Ok(Conversion::TimestampFmt(fmt.to_string())),
+            Some((tag, _)) => Err(SimulationError::ConversionError(format!(
+                "conversion tag `{}` does not accept a `|`-delimited argument",
+                tag
+            ))),
+            None => match s {
+                "bytes" | "string" => Ok(Conversion::Bytes),
+                "int" | "integer" => Ok(Conversion::Integer),
+                "float" => Ok(Conversion::Float),
+                "bool" | "boolean" => Ok(Conversion::Boolean),
+                "timestamp" => Ok(Conversion::Timestamp),
+                other => Err(SimulationError::ConversionError(format!(
+                    "unrecognized conversion `{}`",
+                    other
+                ))),
+            },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Conversion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = String::deserialize(deserializer)?;
+        repr.parse().map_err(de::Error::custom)
+    }
+}
+
+impl fmt::Display for Conversion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Conversion::Bytes => write!(f, "bytes"),
+            Conversion::Integer => write!(f, "int"),
+            Conversion::Float => write!(f, "float"),
+            Conversion::Boolean => write!(f, "bool"),
+            Conversion::Timestamp => write!(f, "timestamp"),
+            Conversion::TimestampFmt(fmt_str) => write!(f, "timestamp|{}", fmt_str),
+        }
+    }
+}
+
+impl Serialize for Conversion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_resolves_simple_tags() {
+        assert_eq!("int".parse::<Conversion>().unwrap(), Conversion::Integer);
+        assert_eq!(
+            "integer".parse::<Conversion>().unwrap(),
+            Conversion::Integer
+        );
+        assert_eq!("float".parse::<Conversion>().unwrap(), Conversion::Float);
+        assert_eq!("bool".parse::<Conversion>().unwrap(), Conversion::Boolean);
+        assert_eq!("bytes".parse::<Conversion>().unwrap(), Conversion::Bytes);
+        assert_eq!(
+            "timestamp".parse::<Conversion>().unwrap(),
+            Conversion::Timestamp
+        );
+    }
+
+    #[test]
+    fn from_str_splits_timestamp_format() {
+        assert_eq!(
+            "timestamp|%Y-%m-%d".parse::<Conversion>().unwrap(),
+            Conversion::TimestampFmt("%Y-%m-%d".to_string())
+        );
+    }
+
+    #[test]
+    fn from_str_rejects_unknown_tag() {
+        assert!("widget".parse::<Conversion>().is_err());
+    }
+
+    #[test]
+    fn from_str_rejects_argument_on_non_timestamp_tag() {
+        assert!("int|%Y".parse::<Conversion>().is_err());
+    }
+
+    #[test]
+    fn convert_parses_typed_values() {
+        assert_eq!(
+            Conversion::Integer.convert("42").unwrap(),
+            Value::Integer(42)
+        );
+        assert_eq!(Conversion::Float.convert("4.5").unwrap(), Value::Float(4.5));
+        assert_eq!(
+            Conversion::Boolean.convert("true").unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            Conversion::Bytes.convert("hi").unwrap(),
+            Value::Bytes("hi".to_string())
+        );
+    }
+
+    #[test]
+    fn convert_surfaces_parse_errors() {
+        assert!(Conversion::Integer.convert("not a number").is_err());
+    }
+
+    #[test]
+    fn convert_parses_timestamp_with_custom_format() {
+        let conversion = Conversion::TimestampFmt("%Y-%m-%d".to_string());
+        assert!(conversion.convert("2024-01-15").is_ok());
+        assert!(conversion.convert("not a date").is_err());
+    }
+
+    #[test]
+    fn display_round_trips_through_convert() {
+        for (conversion, payload) in [
+            (Conversion::Integer, "42"),
+            (Conversion::Float, "4.5"),
+            (Conversion::Boolean, "true"),
+            (Conversion::Bytes, "hi"),
+        ] {
+            let value = conversion.convert(payload).unwrap();
+            assert_eq!(conversion.convert(&value.to_string()).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn display_renders_timestamp_in_the_default_format() {
+        let value = Conversion::Timestamp
+            .convert("2024-01-15T10:30:00")
+            .unwrap();
+        assert_eq!(value.to_string(), "2024-01-15T10:30:00");
+    }
+}
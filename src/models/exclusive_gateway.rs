@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+
+use super::conversion::{Conversion, Value};
+use super::{AsModel, ModelMessage};
+use crate::input_modeling::UniformRNG;
+use crate::utils::error::SimulationError;
+
+/// Routes an incoming message to exactly one of `ports_out`, chosen by
+/// comparing the message's payload - parsed via `conversion` - against
+/// `thresholds`, rather than by string equality. `thresholds[i]` is the
+/// upper bound (exclusive) for `ports_out[i]`; a value exceeding every
+/// threshold routes to the last port. Numeric conversions compare the
+/// parsed number directly; a `timestamp`/`timestamp|...` conversion compares
+/// the parsed instant's Unix epoch seconds. `ports_out` must be non-empty
+/// and `thresholds` must have exactly one fewer entry than `ports_out`,
+/// which is validated at construction/deserialization time.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(try_from = "ExclusiveGatewayRepr")]
+pub struct ExclusiveGateway {
+    port_in: String,
+    ports_out: Vec<String>,
+    conversion: Conversion,
+    thresholds: Vec<f64>,
+    #[serde(skip)]
+    pending: Option<ModelMessage>,
+}
+
+/// The raw, unvalidated YAML shape of an `ExclusiveGateway`, deserialized
+/// first so `TryFrom` can reject a malformed `ports_out`/`thresholds` pairing
+/// before it reaches `route_for`, rather than panicking mid-run.
+#[derive(Deserialize)]
+struct ExclusiveGatewayRepr {
+    port_in: String,
+    ports_out: Vec<String>,
+    conversion: Conversion,
+    thresholds: Vec<f64>,
+}
+
+impl TryFrom<ExclusiveGatewayRepr> for ExclusiveGateway {
+    type Error = SimulationError;
+
+    fn try_from(repr: ExclusiveGatewayRepr) -> Result<Self, SimulationError> {
+        if repr.ports_out.is_empty() {
+            return Err(SimulationError::ConversionError(
+                "exclusive gateway requires at least one port_out".to_string(),
+            ));
+        }
+        if repr.thresholds.len() != repr.ports_out.len() - 1 {
+            return Err(SimulationError::ConversionError(format!(
+                "exclusive gateway has {} ports_out but {} thresholds; expected {}",
+                repr.ports_out.len(),
+                repr.thresholds.len(),
+                repr.ports_out.len() - 1
+            )));
+        }
+        Ok(ExclusiveGateway {
+            port_in: repr.port_in,
+            ports_out: repr.ports_out,
+            conversion: repr.conversion,
+            thresholds: repr.thresholds,
+            pending: None,
+        })
+    }
+}
+
+impl ExclusiveGateway {
+    fn route_for(&self, value: &Value) -> Result<&str, SimulationError> {
+        let numeric = match value {
+            Value::Integer(n) => *n as f64,
+            Value::Float(f) => *f,
+            Value::Timestamp(timestamp) => timestamp.timestamp() as f64,
+            other => {
+                return Err(SimulationError::ConversionError(format!(
+                    "exclusive gateway requires a numeric or timestamp conversion, got {:?}",
+                    other
+                )))
+            }
+        };
+        // `ports_out`/`thresholds` are validated at construction, so
+        // `position` is always in bounds and the `unwrap_or` fallback never
+        // underflows.
+        let port_index = self
+            .thresholds
+            .iter()
+            .position(|threshold| numeric < *threshold)
+            .unwrap_or(self.ports_out.len() - 1);
+        Ok(&self.ports_out[port_index])
+    }
+}
+
+impl AsModel for ExclusiveGateway {
+    fn get_type(&self) -> &'static str {
+        "ExclusiveGateway"
+    }
+
+    fn serialize(&self) -> serde_yaml::Value {
+        serde_yaml::to_value(self).unwrap_or(serde_yaml::Value::Null)
+    }
+
+    fn status(&self) -> String {
+        match &self.pending {
+            Some(_) => "Routing a message".to_string(),
+            None => "Idle".to_string(),
+        }
+    }
+
+    fn events_ext(
+        &mut self,
+        _uniform_rng: &mut UniformRNG,
+        incoming_message: ModelMessage,
+    ) -> Result<Vec<ModelMessage>, SimulationError> {
+        self.pending = Some(incoming_message);
+        Ok(Vec::new())
+    }
+
+    fn events_int(
+        &mut self,
+        _uniform_rng: &mut UniformRNG,
+    ) -> Result<Vec<ModelMessage>, SimulationError> {
+        match self.pending.take() {
+            Some(message) => {
+                let value = self.conversion.convert(message.content())?;
+                let port_out = self.route_for(&value)?.to_string();
+                Ok(vec![ModelMessage::new(
+                    port_out,
+                    message.content().to_string(),
+                )])
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn time_advance(&mut self, _time_delta: f64) {}
+
+    fn until_next_event(&self) -> f64 {
+        if self.pending.is_some() {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gateway(
+        ports_out: Vec<&str>,
+        thresholds: Vec<f64>,
+    ) -> Result<ExclusiveGateway, SimulationError> {
+        ExclusiveGateway::try_from(ExclusiveGatewayRepr {
+            port_in: "in".to_string(),
+            ports_out: ports_out.into_iter().map(str::to_string).collect(),
+            conversion: Conversion::Float,
+            thresholds,
+        })
+    }
+
+    #[test]
+    fn try_from_rejects_no_ports_out() {
+        assert!(gateway(vec![], vec![]).is_err());
+    }
+
+    #[test]
+    fn try_from_rejects_mismatched_threshold_count() {
+        assert!(gateway(vec!["low", "high"], vec![1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn try_from_accepts_matching_counts() {
+        assert!(gateway(vec!["low", "mid", "high"], vec![1.0, 2.0]).is_ok());
+    }
+
+    #[test]
+    fn route_for_picks_the_first_port_whose_threshold_exceeds_the_value() {
+        let gateway = gateway(vec!["low", "mid", "high"], vec![1.0, 2.0]).unwrap();
+        assert_eq!(gateway.route_for(&Value::Float(0.5)).unwrap(), "low");
+        assert_eq!(gateway.route_for(&Value::Float(1.5)).unwrap(), "mid");
+        assert_eq!(gateway.route_for(&Value::Float(2.5)).unwrap(), "high");
+    }
+
+    #[test]
+    fn route_for_compares_timestamps_by_epoch_seconds() {
+        let gateway = gateway(vec!["before", "after"], vec![1_700_000_000.0]).unwrap();
+        let early = Conversion::Timestamp
+            .convert("2020-01-01T00:00:00")
+            .unwrap();
+        let late = Conversion::Timestamp
+            .convert("2024-01-01T00:00:00")
+            .unwrap();
+        assert_eq!(gateway.route_for(&early).unwrap(), "before");
+        assert_eq!(gateway.route_for(&late).unwrap(), "after");
+    }
+
+    #[test]
+    fn route_for_rejects_non_numeric_conversions() {
+        let gateway = gateway(vec!["low", "high"], vec![1.0]).unwrap();
+        assert!(gateway.route_for(&Value::Bytes("hi".to_string())).is_err());
+    }
+}
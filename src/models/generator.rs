@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use super::conversion::Conversion;
+use super::{AsModel, ModelMessage};
+use crate::input_modeling::UniformRNG;
+use crate::utils::error::SimulationError;
+
+/// Periodically emits a message carrying `message_content` unchanged, after
+/// validating it against `conversion` so the emitted payload is a well-formed
+/// instance of the configured type (e.g. a `timestamp|%Y-%m-%d` generator
+/// won't emit a payload that fails to parse as a timestamp).
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Generator {
+    port_out: String,
+    message_content: String,
+    #[serde(default = "Conversion::default_for_generator")]
+    conversion: Conversion,
+    message_interdeparture_time: f64,
+    #[serde(skip, default = "Generator::until_next_exp")]
+    until_next_event: f64,
+}
+
+impl Conversion {
+    fn default_for_generator() -> Self {
+        Conversion::Bytes
+    }
+}
+
+impl Generator {
+    fn until_next_exp() -> f64 {
+        0.0
+    }
+
+    /// Validates (and returns) the typed value this generator is configured
+    /// to emit, per its declared `conversion`.
+    pub fn typed_value(&self) -> Result<super::conversion::Value, SimulationError> {
+        self.conversion.convert(&self.message_content)
+    }
+}
+
+impl AsModel for Generator {
+    fn get_type(&self) -> &'static str {
+        "Generator"
+    }
+
+    fn serialize(&self) -> serde_yaml::Value {
+        serde_yaml::to_value(self).unwrap_or(serde_yaml::Value::Null)
+    }
+
+    fn status(&self) -> String {
+        format!("Generating on port `{}`", self.port_out)
+    }
+
+    fn events_ext(
+        &mut self,
+        _uniform_rng: &mut UniformRNG,
+        _incoming_message: ModelMessage,
+    ) -> Result<Vec<ModelMessage>, SimulationError> {
+        Ok(Vec::new())
+    }
+
+    fn events_int(
+        &mut self,
+        _uniform_rng: &mut UniformRNG,
+    ) -> Result<Vec<ModelMessage>, SimulationError> {
+        // Validates the configured content against the declared conversion
+        // before it's handed off, so a misconfigured generator fails fast
+        // rather than emitting a payload downstream models can't parse.
+        self.typed_value()?;
+        self.until_next_event = self.message_interdeparture_time;
+        Ok(vec![ModelMessage::new(
+            self.port_out.clone(),
+            self.message_content.clone(),
+        )])
+    }
+
+    fn time_advance(&mut self, time_delta: f64) {
+        self.until_next_event -= time_delta;
+    }
+
+    fn until_next_event(&self) -> f64 {
+        self.until_next_event
+    }
+}
@@ -0,0 +1,13 @@
+mod conversion;
+mod exclusive_gateway;
+mod generator;
+#[allow(clippy::module_inception)]
+mod model;
+mod processor;
+pub mod ward;
+
+pub use self::conversion::{Conversion, Value};
+pub use self::exclusive_gateway::ExclusiveGateway;
+pub use self::generator::Generator;
+pub use self::model::{AsModel, Model, ModelClone, ModelFactory};
+pub use self::processor::Processor;
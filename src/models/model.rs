@@ -1,11 +1,50 @@
-use serde::{Serialize, Serializer, Deserialize, Deserializer};
-use serde::ser::SerializeMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
 use serde::de;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::ModelMessage;
 use crate::input_modeling::UniformRNG;
 use crate::utils::error::SimulationError;
 
+/// A factory function that turns the `extra` (non-`id`/`type`) fields of a
+/// model's YAML representation into a boxed `AsModel` trait object.  This is
+/// the unit of registration in the [`ModelFactory`] table - one per
+/// `model_type` tag.
+pub type ModelFactory = fn(serde_yaml::Value) -> Result<Box<dyn AsModel>, SimulationError>;
+
+/// The set of `model_type` tags `Model::deserialize` knows how to construct.
+/// Registered under a `Mutex` rather than exposed directly so that
+/// `register_type` can be called from anywhere, including other crates, at
+/// any point before deserialization happens.
+static MODEL_FACTORIES: Lazy<Mutex<HashMap<&'static str, ModelFactory>>> = Lazy::new(|| {
+    let mut factories: HashMap<&'static str, ModelFactory> = HashMap::new();
+    factories.insert("Generator", |extra| {
+        let generator =
+            serde_yaml::from_value::<super::Generator>(extra).map_err(SimulationError::from)?;
+        Ok(Box::new(generator))
+    });
+    factories.insert("ExclusiveGateway", |extra| {
+        let exclusive_gateway = serde_yaml::from_value::<super::ExclusiveGateway>(extra)
+            .map_err(SimulationError::from)?;
+        Ok(Box::new(exclusive_gateway))
+    });
+    factories.insert("Processor", |extra| {
+        let processor =
+            serde_yaml::from_value::<super::Processor>(extra).map_err(SimulationError::from)?;
+        Ok(Box::new(processor))
+    });
+    factories.insert("Storage", |extra| {
+        let storage =
+            serde_yaml::from_value::<super::Storage>(extra).map_err(SimulationError::from)?;
+        Ok(Box::new(storage))
+    });
+    Mutex::new(factories)
+});
+
 /// `Model` wraps `model_type` and provides common ID functionality (a struct
 /// field and associated accessor method).  The simulator requires all models
 /// to have an ID.
@@ -23,6 +62,18 @@ impl Model {
     pub fn id(&self) -> &str {
         self.id.as_str()
     }
+
+    /// Registers a `ModelFactory` under `tag`, so that `Model::deserialize`
+    /// will recognize a `type: <tag>` entry in a model's YAML representation
+    /// and construct it via `factory`. Registering the same tag twice
+    /// overwrites the previous factory, which lets a downstream crate
+    /// replace a built-in model type if it needs to.
+    pub fn register_type(tag: &'static str, factory: ModelFactory) {
+        MODEL_FACTORIES
+            .lock()
+            .expect("model factory registry lock poisoned")
+            .insert(tag, factory);
+    }
 }
 
 pub trait ModelClone {
@@ -62,40 +113,32 @@ impl Serialize for Model {
 impl<'de> Deserialize<'de> for Model {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let model_repr = super::ModelRepr::deserialize(deserializer)?;
-        const VARIANTS: &'static [&'static str] = &[
-            &"Generator", &"ExclusiveGateway", &"Processor", &"Storage"
-        ];
-        match &model_repr.model_type[..] {
-            "Generator" => {
-                let generator = serde_yaml::from_value::<super::Generator>(model_repr.extra).map_err(de::Error::custom)?;
-                Ok(Model::new(
-                    model_repr.id,
-                    Box::new(generator)
-                ))
-            },
-            "ExclusiveGateway" => {
-                let exclusive_gateway = serde_yaml::from_value::<super::ExclusiveGateway>(model_repr.extra).map_err(de::Error::custom)?;
-                Ok(Model::new(
-                    model_repr.id,
-                    Box::new(exclusive_gateway)
-                ))
-            },
-            "Processor" => {
-                let processor = serde_yaml::from_value::<super::Processor>(model_repr.extra).map_err(de::Error::custom)?;
-                Ok(Model::new(
-                    model_repr.id,
-                    Box::new(processor)
-                ))
-            },
-            "Storage" => {
-                let storage = serde_yaml::from_value::<super::Storage>(model_repr.extra).map_err(de::Error::custom)?;
-                Ok(Model::new(
-                    model_repr.id,
-                    Box::new(storage)
-                ))
-            },
-            other => {
-                Err(de::Error::unknown_variant(other, VARIANTS))
+        // Copy the factory pointer out and release the lock before calling
+        // it: a factory may itself recursively deserialize a `Model` (e.g.
+        // a composite/coupled model type), and holding the guard across
+        // that call would deadlock on this non-reentrant mutex.
+        let factory = {
+            let factories = MODEL_FACTORIES
+                .lock()
+                .expect("model factory registry lock poisoned");
+            factories.get(&model_repr.model_type[..]).copied()
+        };
+        match factory {
+            Some(factory) => {
+                let inner = factory(model_repr.extra).map_err(de::Error::custom)?;
+                Ok(Model::new(model_repr.id, inner))
+            }
+            None => {
+                let factories = MODEL_FACTORIES
+                    .lock()
+                    .expect("model factory registry lock poisoned");
+                let mut known_variants: Vec<&'static str> = factories.keys().copied().collect();
+                known_variants.sort_unstable();
+                Err(de::Error::custom(format!(
+                    "unknown model type `{}`, expected one of registered types: `{}`",
+                    model_repr.model_type,
+                    known_variants.join("`, `")
+                )))
             }
         }
     }
@@ -130,6 +173,67 @@ impl AsModel for Model {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Noop;
+
+    impl AsModel for Noop {
+        fn status(&self) -> String {
+            "noop".to_string()
+        }
+
+        fn events_ext(
+            &mut self,
+            _uniform_rng: &mut UniformRNG,
+            _incoming_message: ModelMessage,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            Ok(Vec::new())
+        }
+
+        fn events_int(
+            &mut self,
+            _uniform_rng: &mut UniformRNG,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            Ok(Vec::new())
+        }
+
+        fn time_advance(&mut self, _time_delta: f64) {}
+
+        fn until_next_event(&self) -> f64 {
+            f64::INFINITY
+        }
+    }
+
+    #[test]
+    fn register_type_lets_deserialize_recognize_a_new_tag() {
+        Model::register_type("NoopA", |_extra| Ok(Box::new(Noop)));
+        let model: Model = serde_yaml::from_str("id: n1\ntype: NoopA\n").unwrap();
+        assert_eq!(model.id(), "n1");
+        assert_eq!(model.status(), "noop");
+    }
+
+    #[test]
+    fn register_type_overwrites_an_existing_tag() {
+        Model::register_type("NoopB", |_extra| Ok(Box::new(Noop)));
+        Model::register_type("NoopB", |_extra| {
+            Err(SimulationError::ConversionError("replaced".to_string()))
+        });
+        let result = serde_yaml::from_str::<Model>("id: n1\ntype: NoopB\n");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_unknown_type_lists_registered_tags() {
+        let err = serde_yaml::from_str::<Model>("id: x\ntype: NotAThing\n").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("unknown model type `NotAThing`"));
+        assert!(message.contains("Generator"));
+    }
+}
+
 /// The `AsModel` trait defines everything required for a model to operate
 /// within the discrete event simulation.  The simulator formalism (Discrete
 /// Event System Specification) requires `events_ext`, `events_int`,
@@ -0,0 +1,151 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use super::conversion::{Conversion, Value};
+use super::{AsModel, ModelMessage};
+use crate::input_modeling::UniformRNG;
+use crate::utils::error::SimulationError;
+
+/// Queues incoming messages and re-emits them after a service delay.
+/// Incoming payloads are parsed via `conversion` on arrival, so downstream
+/// processing logic can branch on the typed `Value` (a numeric threshold, a
+/// timestamp, ...) rather than the raw string payload. The raw payload is
+/// kept alongside the parsed `Value` and is what actually gets re-emitted,
+/// so a custom `conversion` (e.g. `timestamp|%Y-%m-%d`) round-trips exactly
+/// rather than being reformatted through `Value`'s default rendering.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Processor {
+    port_in: String,
+    port_out: String,
+    conversion: Conversion,
+    service_time: f64,
+    #[serde(skip)]
+    queue: VecDeque<(String, Value)>,
+    #[serde(skip, default = "idle_until_next_event")]
+    until_next_event: f64,
+}
+
+fn idle_until_next_event() -> f64 {
+    f64::INFINITY
+}
+
+impl Processor {
+    /// The raw payloads and their parsed `Value`s currently queued for
+    /// processing.
+    pub fn queue(&self) -> &VecDeque<(String, Value)> {
+        &self.queue
+    }
+
+    /// Parses `payload` per `conversion` and queues it (alongside the raw
+    /// string) for processing, starting the service-time countdown if the
+    /// queue was previously empty.
+    fn enqueue(&mut self, payload: String) -> Result<(), SimulationError> {
+        let value = self.conversion.convert(&payload)?;
+        self.queue.push_back((payload, value));
+        if self.until_next_event.is_infinite() {
+            self.until_next_event = self.service_time;
+        }
+        Ok(())
+    }
+
+    /// Pops the head of the queue, re-arms the service-time countdown for
+    /// whatever remains, and returns a message carrying the original raw
+    /// payload - not `Value`'s rendering of it - so a conversion like
+    /// `timestamp|%Y-%m-%d` round-trips exactly.
+    fn dequeue(&mut self) -> Option<ModelMessage> {
+        let processed = self.queue.pop_front();
+        self.until_next_event = if self.queue.is_empty() {
+            f64::INFINITY
+        } else {
+            self.service_time
+        };
+        processed.map(|(payload, _value)| ModelMessage::new(self.port_out.clone(), payload))
+    }
+}
+
+impl AsModel for Processor {
+    fn get_type(&self) -> &'static str {
+        "Processor"
+    }
+
+    fn serialize(&self) -> serde_yaml::Value {
+        serde_yaml::to_value(self).unwrap_or(serde_yaml::Value::Null)
+    }
+
+    fn status(&self) -> String {
+        format!("{} message(s) queued", self.queue.len())
+    }
+
+    fn events_ext(
+        &mut self,
+        _uniform_rng: &mut UniformRNG,
+        incoming_message: ModelMessage,
+    ) -> Result<Vec<ModelMessage>, SimulationError> {
+        self.enqueue(incoming_message.content().to_string())?;
+        Ok(Vec::new())
+    }
+
+    fn events_int(
+        &mut self,
+        _uniform_rng: &mut UniformRNG,
+    ) -> Result<Vec<ModelMessage>, SimulationError> {
+        Ok(self.dequeue().into_iter().collect())
+    }
+
+    fn time_advance(&mut self, time_delta: f64) {
+        if self.until_next_event.is_finite() {
+            self.until_next_event -= time_delta;
+        }
+    }
+
+    fn until_next_event(&self) -> f64 {
+        self.until_next_event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn processor(conversion: Conversion) -> Processor {
+        Processor {
+            port_in: "in".to_string(),
+            port_out: "out".to_string(),
+            conversion,
+            service_time: 1.0,
+            queue: VecDeque::new(),
+            until_next_event: f64::INFINITY,
+        }
+    }
+
+    #[test]
+    fn enqueue_parses_the_payload_into_the_declared_conversion() {
+        let mut processor = processor(Conversion::Integer);
+        processor.enqueue("42".to_string()).unwrap();
+        assert_eq!(
+            processor.queue().front().unwrap(),
+            &("42".to_string(), Value::Integer(42))
+        );
+    }
+
+    #[test]
+    fn enqueue_surfaces_conversion_errors() {
+        let mut processor = processor(Conversion::Integer);
+        assert!(processor.enqueue("not a number".to_string()).is_err());
+    }
+
+    #[test]
+    fn dequeue_reemits_the_raw_payload_not_values_default_rendering() {
+        let mut processor = processor(Conversion::TimestampFmt("%Y-%m-%d".to_string()));
+        processor.enqueue("2024-01-15".to_string()).unwrap();
+        let message = processor.dequeue().unwrap();
+        assert_eq!(message.content(), "2024-01-15");
+    }
+
+    #[test]
+    fn dequeue_returns_none_once_the_queue_is_empty() {
+        let mut processor = processor(Conversion::Bytes);
+        assert!(processor.dequeue().is_none());
+    }
+}
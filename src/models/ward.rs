@@ -0,0 +1,190 @@
+use super::{AsModel, Model};
+
+/// The outcome of evaluating a [`Ward`] against the current state of the
+/// simulation.
+pub enum WardDecision {
+    /// Nothing of note - the run should keep going.
+    Continue,
+    /// The run should halt immediately; the `String` is surfaced to the
+    /// caller as the reason the simulation stopped.
+    Stop(String),
+    /// The run should keep going, but the `String` is recorded for
+    /// reporting alongside the eventual result.
+    Flag(String),
+}
+
+/// A `Ward` is a termination criterion that `Simulation` evaluates after
+/// every internal transition, once the messages it emitted have been routed.
+/// The external transitions that routing triggers are not evaluated
+/// separately. Unlike a fixed simulation horizon, wards let a run
+/// stop (or flag something worth noting) as soon as the models themselves
+/// reach an interesting state.
+pub trait Ward {
+    fn evaluate(&mut self, time: f64, models: &[Model]) -> WardDecision;
+}
+
+/// Stops the run once the simulation clock reaches `max_time`.
+pub struct MaxTimeWard {
+    max_time: f64,
+}
+
+impl MaxTimeWard {
+    pub fn new(max_time: f64) -> Self {
+        Self { max_time }
+    }
+}
+
+impl Ward for MaxTimeWard {
+    fn evaluate(&mut self, time: f64, _models: &[Model]) -> WardDecision {
+        if time >= self.max_time {
+            WardDecision::Stop(format!(
+                "simulation time {} reached the configured maximum of {}",
+                time, self.max_time
+            ))
+        } else {
+            WardDecision::Continue
+        }
+    }
+}
+
+/// Stops the run once it has been evaluated `max_events` times, i.e. after
+/// that many internal transitions have occurred.
+pub struct MaxEventCountWard {
+    max_events: u64,
+    event_count: u64,
+}
+
+impl MaxEventCountWard {
+    pub fn new(max_events: u64) -> Self {
+        Self {
+            max_events,
+            event_count: 0,
+        }
+    }
+}
+
+impl Ward for MaxEventCountWard {
+    fn evaluate(&mut self, _time: f64, _models: &[Model]) -> WardDecision {
+        self.event_count += 1;
+        if self.event_count >= self.max_events {
+            WardDecision::Stop(format!(
+                "event count {} reached the configured maximum of {}",
+                self.event_count, self.max_events
+            ))
+        } else {
+            WardDecision::Continue
+        }
+    }
+}
+
+/// Stops the run as soon as any model's `status()` satisfies `predicate`.
+pub struct StatusPredicateWard<F: FnMut(&str) -> bool> {
+    predicate: F,
+    reason: String,
+}
+
+impl<F: FnMut(&str) -> bool> StatusPredicateWard<F> {
+    pub fn new(reason: impl Into<String>, predicate: F) -> Self {
+        Self {
+            predicate,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl<F: FnMut(&str) -> bool> Ward for StatusPredicateWard<F> {
+    fn evaluate(&mut self, _time: f64, models: &[Model]) -> WardDecision {
+        for model in models {
+            if (self.predicate)(&model.status()) {
+                return WardDecision::Stop(format!(
+                    "model `{}` matched status predicate: {}",
+                    model.id(),
+                    self.reason
+                ));
+            }
+        }
+        WardDecision::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelMessage;
+    use crate::utils::error::SimulationError;
+
+    #[derive(Clone)]
+    struct StatusModel(&'static str);
+
+    impl AsModel for StatusModel {
+        fn status(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn events_ext(
+            &mut self,
+            _uniform_rng: &mut crate::input_modeling::UniformRNG,
+            _incoming_message: ModelMessage,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            Ok(Vec::new())
+        }
+
+        fn events_int(
+            &mut self,
+            _uniform_rng: &mut crate::input_modeling::UniformRNG,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            Ok(Vec::new())
+        }
+
+        fn time_advance(&mut self, _time_delta: f64) {}
+
+        fn until_next_event(&self) -> f64 {
+            f64::INFINITY
+        }
+    }
+
+    fn decision_is_continue(decision: WardDecision) -> bool {
+        matches!(decision, WardDecision::Continue)
+    }
+
+    fn decision_is_stop(decision: WardDecision) -> bool {
+        matches!(decision, WardDecision::Stop(_))
+    }
+
+    #[test]
+    fn max_time_ward_continues_before_the_limit_and_stops_at_it() {
+        let mut ward = MaxTimeWard::new(10.0);
+        assert!(decision_is_continue(ward.evaluate(5.0, &[])));
+        assert!(decision_is_stop(ward.evaluate(10.0, &[])));
+    }
+
+    #[test]
+    fn max_event_count_ward_stops_once_the_count_is_reached() {
+        let mut ward = MaxEventCountWard::new(2);
+        assert!(decision_is_continue(ward.evaluate(0.0, &[])));
+        assert!(decision_is_stop(ward.evaluate(0.0, &[])));
+    }
+
+    #[test]
+    fn status_predicate_ward_stops_when_a_model_matches() {
+        let models = vec![
+            Model::new("a".to_string(), Box::new(StatusModel("Idle"))),
+            Model::new("b".to_string(), Box::new(StatusModel("Done"))),
+        ];
+        let mut ward = StatusPredicateWard::new("reached Done", |status| status == "Done");
+        match ward.evaluate(0.0, &models) {
+            WardDecision::Stop(reason) => {
+                assert!(reason.contains("model `b`"));
+                assert!(reason.contains("reached Done"));
+            }
+            _ => panic!("expected a Stop decision"),
+        }
+    }
+
+    #[test]
+    fn status_predicate_ward_continues_when_nothing_matches() {
+        let models = vec![Model::new("a".to_string(), Box::new(StatusModel("Idle")))];
+        let mut ward = StatusPredicateWard::new("reached Done", |status| status == "Done");
+        assert!(decision_is_continue(ward.evaluate(0.0, &models)));
+    }
+}
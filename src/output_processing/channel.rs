@@ -0,0 +1,66 @@
+use crossbeam::channel::{Receiver, Sender};
+
+use super::{OutputProcessor, StepRecord};
+use crate::utils::error::SimulationError;
+
+/// Pushes each `StepRecord` onto a `crossbeam` channel instead of writing it
+/// out, so a live consumer on another thread can read the stream as it's
+/// produced.
+pub struct ChannelOutputProcessor {
+    sender: Sender<StepRecord>,
+}
+
+impl ChannelOutputProcessor {
+    /// Creates a processor paired with the `Receiver` a consumer should poll.
+    pub fn new() -> (Self, Receiver<StepRecord>) {
+        let (sender, receiver) = crossbeam::channel::unbounded();
+        (Self { sender }, receiver)
+    }
+}
+
+impl OutputProcessor for ChannelOutputProcessor {
+    fn record(&mut self, rec: StepRecord) -> Result<(), SimulationError> {
+        self.sender
+            .send(rec)
+            .map_err(|err| SimulationError::OutputProcessingError(err.to_string()))
+    }
+
+    fn finish(&mut self) -> Result<(), SimulationError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelMessage;
+
+    #[test]
+    fn record_pushes_onto_the_paired_receiver() {
+        let (mut processor, receiver) = ChannelOutputProcessor::new();
+        processor
+            .record(StepRecord {
+                time: 1.5,
+                model_id: "m1".to_string(),
+                status: "Idle".to_string(),
+                emitted: vec![ModelMessage::new("out".to_string(), "42".to_string())],
+            })
+            .unwrap();
+        let rec = receiver.try_recv().unwrap();
+        assert_eq!(rec.model_id, "m1");
+        assert_eq!(rec.time, 1.5);
+    }
+
+    #[test]
+    fn record_errors_once_the_receiver_is_dropped() {
+        let (mut processor, receiver) = ChannelOutputProcessor::new();
+        drop(receiver);
+        let result = processor.record(StepRecord {
+            time: 0.0,
+            model_id: "m1".to_string(),
+            status: "Idle".to_string(),
+            emitted: Vec::new(),
+        });
+        assert!(result.is_err());
+    }
+}
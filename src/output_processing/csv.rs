@@ -0,0 +1,88 @@
+use std::io::Write;
+
+use serde::Serialize;
+
+use super::{OutputProcessor, StepRecord};
+use crate::utils::error::SimulationError;
+
+/// A flattened, CSV-friendly view of a `StepRecord` - `emitted` is rendered
+/// as the debug representation of each message, joined by `;`, since CSV
+/// rows can't hold a nested list directly.
+#[derive(Serialize)]
+struct CsvRow {
+    time: f64,
+    model_id: String,
+    status: String,
+    emitted: String,
+}
+
+impl From<StepRecord> for CsvRow {
+    fn from(rec: StepRecord) -> Self {
+        let emitted = rec
+            .emitted
+            .iter()
+            .map(|message| format!("{:?}", message))
+            .collect::<Vec<_>>()
+            .join(";");
+        CsvRow {
+            time: rec.time,
+            model_id: rec.model_id,
+            status: rec.status,
+            emitted,
+        }
+    }
+}
+
+/// Serializes each `StepRecord` as a CSV row, written to `sink` as the
+/// records arrive.
+pub struct CsvOutputProcessor<W: Write> {
+    writer: ::csv::Writer<W>,
+}
+
+impl<W: Write> CsvOutputProcessor<W> {
+    pub fn new(sink: W) -> Self {
+        Self {
+            writer: ::csv::Writer::from_writer(sink),
+        }
+    }
+}
+
+impl<W: Write> OutputProcessor for CsvOutputProcessor<W> {
+    fn record(&mut self, rec: StepRecord) -> Result<(), SimulationError> {
+        self.writer.serialize(CsvRow::from(rec))?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), SimulationError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelMessage;
+
+    #[test]
+    fn record_writes_a_flattened_csv_row() {
+        let mut processor = CsvOutputProcessor::new(Vec::new());
+        processor
+            .record(StepRecord {
+                time: 1.5,
+                model_id: "m1".to_string(),
+                status: "Idle".to_string(),
+                emitted: vec![ModelMessage::new("out".to_string(), "42".to_string())],
+            })
+            .unwrap();
+        processor.finish().unwrap();
+        let written = String::from_utf8(processor.writer.into_inner().unwrap()).unwrap();
+        let mut lines = written.lines();
+        assert_eq!(lines.next().unwrap(), "time,model_id,status,emitted");
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("1.5,m1,Idle,"));
+        assert!(row.contains("out"));
+        assert!(row.contains("42"));
+        assert!(lines.next().is_none());
+    }
+}
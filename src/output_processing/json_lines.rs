@@ -0,0 +1,62 @@
+use std::io::Write;
+
+use super::{OutputProcessor, StepRecord};
+use crate::utils::error::SimulationError;
+
+/// Serializes each `StepRecord` as a single line of JSON, written to `sink`
+/// as the records arrive (the "JSON Lines" / `.jsonl` convention).
+pub struct JsonLinesOutputProcessor<W: Write> {
+    sink: W,
+}
+
+impl<W: Write> JsonLinesOutputProcessor<W> {
+    pub fn new(sink: W) -> Self {
+        Self { sink }
+    }
+}
+
+impl<W: Write> OutputProcessor for JsonLinesOutputProcessor<W> {
+    fn record(&mut self, rec: StepRecord) -> Result<(), SimulationError> {
+        let line = serde_json::to_string(&rec)?;
+        writeln!(self.sink, "{}", line)?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), SimulationError> {
+        self.sink.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelMessage;
+
+    #[test]
+    fn record_writes_one_json_line_per_call() {
+        let mut processor = JsonLinesOutputProcessor::new(Vec::new());
+        processor
+            .record(StepRecord {
+                time: 1.5,
+                model_id: "m1".to_string(),
+                status: "Idle".to_string(),
+                emitted: vec![ModelMessage::new("out".to_string(), "42".to_string())],
+            })
+            .unwrap();
+        processor
+            .record(StepRecord {
+                time: 2.0,
+                model_id: "m2".to_string(),
+                status: "Done".to_string(),
+                emitted: Vec::new(),
+            })
+            .unwrap();
+        processor.finish().unwrap();
+        let written = String::from_utf8(processor.sink).unwrap();
+        let lines: Vec<&str> = written.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("\"model_id\":\"m1\""));
+        assert!(lines[1].contains("\"model_id\":\"m2\""));
+    }
+}
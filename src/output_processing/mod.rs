@@ -0,0 +1,37 @@
+mod channel;
+mod csv;
+mod json_lines;
+mod yaml;
+
+pub use self::channel::ChannelOutputProcessor;
+pub use self::csv::CsvOutputProcessor;
+pub use self::json_lines::JsonLinesOutputProcessor;
+pub use self::yaml::YamlOutputProcessor;
+
+use serde::Serialize;
+
+use crate::models::ModelMessage;
+use crate::utils::error::SimulationError;
+
+/// A single transition's worth of telemetry: the clock at which it
+/// happened, which model produced it, that model's resulting `status()`,
+/// and whatever messages it emitted. Produced after every internal and
+/// external transition in the step loop and handed to each registered
+/// `OutputProcessor`.
+#[derive(Clone, Serialize)]
+pub struct StepRecord {
+    pub time: f64,
+    pub model_id: String,
+    pub status: String,
+    pub emitted: Vec<ModelMessage>,
+}
+
+/// Receives a [`StepRecord`] after every transition, so the simulator can be
+/// used as a pipe-able data source instead of requiring callers to poll
+/// model state manually. Both methods return a `Result` so a write or
+/// serialization failure (a full disk, a broken pipe, a dropped channel
+/// receiver) is surfaced to the driver rather than silently dropped.
+pub trait OutputProcessor {
+    fn record(&mut self, rec: StepRecord) -> Result<(), SimulationError>;
+    fn finish(&mut self) -> Result<(), SimulationError>;
+}
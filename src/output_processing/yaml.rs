@@ -0,0 +1,53 @@
+use std::io::Write;
+
+use super::{OutputProcessor, StepRecord};
+use crate::utils::error::SimulationError;
+
+/// Serializes each `StepRecord` as its own YAML document, written to `sink`
+/// as the records arrive, so the stream can be consumed incrementally rather
+/// than buffered until the run finishes.
+pub struct YamlOutputProcessor<W: Write> {
+    sink: W,
+}
+
+impl<W: Write> YamlOutputProcessor<W> {
+    pub fn new(sink: W) -> Self {
+        Self { sink }
+    }
+}
+
+impl<W: Write> OutputProcessor for YamlOutputProcessor<W> {
+    fn record(&mut self, rec: StepRecord) -> Result<(), SimulationError> {
+        let doc = serde_yaml::to_string(&rec)?;
+        writeln!(self.sink, "{}", doc)?;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), SimulationError> {
+        self.sink.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelMessage;
+
+    #[test]
+    fn record_writes_one_yaml_document_per_call() {
+        let mut processor = YamlOutputProcessor::new(Vec::new());
+        processor
+            .record(StepRecord {
+                time: 1.5,
+                model_id: "m1".to_string(),
+                status: "Idle".to_string(),
+                emitted: vec![ModelMessage::new("out".to_string(), "42".to_string())],
+            })
+            .unwrap();
+        processor.finish().unwrap();
+        let written = String::from_utf8(processor.sink).unwrap();
+        assert!(written.contains("model_id: m1"));
+        assert!(written.contains("time: 1.5"));
+    }
+}
@@ -0,0 +1,3 @@
+mod realtime;
+
+pub use self::realtime::RealtimeRunner;
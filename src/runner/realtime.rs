@@ -0,0 +1,188 @@
+use std::time::{Duration, Instant};
+
+use crossbeam::channel::Receiver;
+
+use crate::connectors::Connector;
+use crate::input_modeling::UniformRNG;
+use crate::models::{AsModel, Model, ModelMessage};
+use crate::utils::error::SimulationError;
+use crate::utils::routing;
+
+/// Paces `time_advance` to real (wall-clock) seconds instead of running the
+/// model network as fast as possible, and accepts external stimuli off a
+/// channel between steps. Intended for interactive or hardware-in-the-loop
+/// use, where an external event loop (a socket, a GPIO reader, ...) feeds
+/// live inputs into running models via `external_events`.
+pub struct RealtimeRunner {
+    models: Vec<Model>,
+    connectors: Vec<Connector>,
+    uniform_rng: UniformRNG,
+    external_events: Receiver<(String, ModelMessage)>,
+    time_scale: f64,
+    clock: f64,
+    wall_clock_origin: Instant,
+}
+
+impl RealtimeRunner {
+    /// `time_scale` is the number of simulation seconds per wall-clock
+    /// second; `1.0` runs in lock-step with real time, `2.0` runs twice as
+    /// fast, `0.5` runs at half speed.
+    pub fn new(
+        models: Vec<Model>,
+        connectors: Vec<Connector>,
+        uniform_rng: UniformRNG,
+        external_events: Receiver<(String, ModelMessage)>,
+        time_scale: f64,
+    ) -> Self {
+        Self {
+            models,
+            connectors,
+            uniform_rng,
+            external_events,
+            time_scale,
+            clock: 0.0,
+            wall_clock_origin: Instant::now(),
+        }
+    }
+
+    pub fn time(&self) -> f64 {
+        self.clock
+    }
+
+    fn elapsed_sim_seconds(&self) -> f64 {
+        self.wall_clock_origin.elapsed().as_secs_f64() * self.time_scale
+    }
+
+    fn until_next_event(&self) -> f64 {
+        self.models
+            .iter()
+            .map(AsModel::until_next_event)
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Delivers `messages` (emitted by `source_id`) to every model connected
+    /// downstream of it, the same way `Simulation::route` does for the
+    /// as-fast-as-possible driver.
+    fn route(
+        &mut self,
+        source_id: &str,
+        messages: Vec<ModelMessage>,
+    ) -> Result<(), SimulationError> {
+        for message in messages {
+            let targets = routing::targets_for(&self.connectors, source_id);
+            for target_id in targets {
+                let re_emitted = match self.models.iter_mut().find(|model| model.id() == target_id)
+                {
+                    Some(target) => target.events_ext(&mut self.uniform_rng, message.clone())?,
+                    None => continue,
+                };
+                self.route(&target_id, re_emitted)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn dispatch_external(
+        &mut self,
+        model_id: &str,
+        message: ModelMessage,
+    ) -> Result<(), SimulationError> {
+        let emitted = match self.models.iter_mut().find(|model| model.id() == model_id) {
+            Some(model) => model.events_ext(&mut self.uniform_rng, message)?,
+            None => return Ok(()),
+        };
+        self.route(model_id, emitted)
+    }
+
+    /// Drains any external messages that have arrived, then, if the earliest
+    /// next event is due, advances the clock to it and fires the internal
+    /// transitions of the models due at that time, routing whatever they
+    /// emit to connected models. One event time is handled per call; later
+    /// events that are already due wait for the next call. Non-
+    /// blocking, so a host application can call it from its own loop rather
+    /// than handing control over to the runner.
+    pub fn poll(&mut self) -> Result<(), SimulationError> {
+        while let Ok((model_id, message)) = self.external_events.try_recv() {
+            self.dispatch_external(&model_id, message)?;
+        }
+
+        let due_in = self.until_next_event();
+        let elapsed = self.elapsed_sim_seconds();
+        if elapsed < due_in {
+            return Ok(());
+        }
+
+        let time_delta = due_in;
+        let due: Vec<String> = {
+            for model in self.models.iter_mut() {
+                model.time_advance(time_delta);
+            }
+            self.models
+                .iter()
+                .filter(|model| model.until_next_event() <= 0.0)
+                .map(|model| model.id().to_string())
+                .collect()
+        };
+        for model_id in due {
+            let emitted = {
+                let model = self
+                    .models
+                    .iter_mut()
+                    .find(|model| model.id() == model_id)
+                    .expect("model present in `due` must still be in `self.models`");
+                model.events_int(&mut self.uniform_rng)?
+            };
+            self.route(&model_id, emitted)?;
+        }
+        self.clock += time_delta;
+        self.wall_clock_origin =
+            advance_origin(self.wall_clock_origin, time_delta, self.time_scale);
+        Ok(())
+    }
+}
+
+/// Advances `origin` by the wall-clock time `time_delta` simulated seconds
+/// actually consumes, rather than snapping it to `Instant::now()`: if the
+/// host calls `poll()` on a coarser cadence than the event rate, any
+/// wall-clock time elapsed beyond `time_delta` carries forward instead of
+/// being discarded, so the sim clock catches back up rather than
+/// permanently drifting behind real time.
+fn advance_origin(origin: Instant, time_delta: f64, time_scale: f64) -> Instant {
+    origin + Duration::from_secs_f64(time_delta / time_scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_origin_moves_forward_by_the_consumed_simulated_time() {
+        let origin = Instant::now();
+        let advanced = advance_origin(origin, 2.0, 1.0);
+        assert_eq!(advanced - origin, Duration::from_secs_f64(2.0));
+    }
+
+    #[test]
+    fn advance_origin_accounts_for_time_scale() {
+        let origin = Instant::now();
+        // At 2x speed, 2 simulated seconds consume 1 wall-clock second.
+        let advanced = advance_origin(origin, 2.0, 2.0);
+        assert_eq!(advanced - origin, Duration::from_secs_f64(1.0));
+    }
+
+    #[test]
+    fn advance_origin_carries_forward_residual_elapsed_time() {
+        // A host polling on a coarser cadence than the event rate: by the
+        // time `poll()` is called, wall-clock has already run ahead of
+        // `due_in`. Advancing the origin by only `due_in` (not resetting it
+        // to `Instant::now()`) preserves that residual so the sim clock
+        // catches back up instead of losing it.
+        let due_in = 1.0;
+        let overshoot = Duration::from_millis(250);
+        let origin = Instant::now() - Duration::from_secs_f64(due_in) - overshoot;
+        let advanced = advance_origin(origin, due_in, 1.0);
+        let now = Instant::now();
+        assert!(advanced < now);
+        assert!(now - advanced >= overshoot);
+    }
+}
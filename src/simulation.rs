@@ -0,0 +1,296 @@
+use crate::connectors::Connector;
+use crate::input_modeling::UniformRNG;
+use crate::models::ward::{Ward, WardDecision};
+use crate::models::{AsModel, Model, ModelMessage};
+use crate::output_processing::{OutputProcessor, StepRecord};
+use crate::utils::error::SimulationError;
+use crate::utils::routing;
+
+/// The outcome of a finished [`Simulation::run`]: why the run stopped, plus
+/// any `Flag`s raised by wards along the way.
+pub struct SimulationResult {
+    pub reason: String,
+    pub flags: Vec<String>,
+}
+
+/// Drives a network of `Model`s forward in time, routing messages between
+/// them via `connectors` and checking every registered `Ward` after each
+/// internal transition and the routing of its messages; external
+/// transitions are not checked separately. The first `WardDecision::Stop`
+/// halts the run.
+pub struct Simulation {
+    models: Vec<Model>,
+    connectors: Vec<Connector>,
+    uniform_rng: UniformRNG,
+    wards: Vec<Box<dyn Ward>>,
+    output_processors: Vec<Box<dyn OutputProcessor>>,
+    clock: f64,
+}
+
+impl Simulation {
+    pub fn new(models: Vec<Model>, connectors: Vec<Connector>, uniform_rng: UniformRNG) -> Self {
+        Self {
+            models,
+            connectors,
+            uniform_rng,
+            wards: Vec::new(),
+            output_processors: Vec::new(),
+            clock: 0.0,
+        }
+    }
+
+    /// Registers a `Ward`, evaluated after every transition for the
+    /// remainder of the run.
+    pub fn add_ward(&mut self, ward: Box<dyn Ward>) {
+        self.wards.push(ward);
+    }
+
+    /// Registers an `OutputProcessor`, fed a `StepRecord` after every
+    /// transition for the remainder of the run and given a chance to flush
+    /// on `Simulation::run`'s exit.
+    pub fn add_output_processor(&mut self, output_processor: Box<dyn OutputProcessor>) {
+        self.output_processors.push(output_processor);
+    }
+
+    fn emit_record(
+        &mut self,
+        model_id: &str,
+        status: String,
+        emitted: Vec<ModelMessage>,
+    ) -> Result<(), SimulationError> {
+        let rec = StepRecord {
+            time: self.clock,
+            model_id: model_id.to_string(),
+            status,
+            emitted,
+        };
+        for output_processor in self.output_processors.iter_mut() {
+            output_processor.record(rec.clone())?;
+        }
+        Ok(())
+    }
+
+    pub fn time(&self) -> f64 {
+        self.clock
+    }
+
+    fn route(
+        &mut self,
+        source_id: &str,
+        messages: Vec<ModelMessage>,
+    ) -> Result<(), SimulationError> {
+        for message in messages {
+            let targets = routing::targets_for(&self.connectors, source_id);
+            for target_id in targets {
+                let (status, re_emitted) =
+                    match self.models.iter_mut().find(|model| model.id() == target_id) {
+                        Some(target) => {
+                            let re_emitted =
+                                target.events_ext(&mut self.uniform_rng, message.clone())?;
+                            (target.status(), re_emitted)
+                        }
+                        None => continue,
+                    };
+                self.emit_record(&target_id, status, re_emitted.clone())?;
+                self.route(&target_id, re_emitted)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates every registered ward against the current clock and model
+    /// states. Returns `Some(reason)` on the first `Stop`; any `Flag`s
+    /// encountered along the way are appended to `flags`.
+    fn check_wards(&mut self, flags: &mut Vec<String>) -> Option<String> {
+        for ward in self.wards.iter_mut() {
+            match ward.evaluate(self.clock, &self.models) {
+                WardDecision::Stop(reason) => return Some(reason),
+                WardDecision::Flag(reason) => flags.push(reason),
+                WardDecision::Continue => {}
+            }
+        }
+        None
+    }
+
+    /// Advances to the next due event, fires its transition(s), routes any
+    /// emitted messages, and checks wards. Returns `Some(reason)` (plus any
+    /// flags raised) once a ward calls for a stop.
+    fn step(&mut self, flags: &mut Vec<String>) -> Result<Option<String>, SimulationError> {
+        let time_delta = self
+            .models
+            .iter()
+            .map(AsModel::until_next_event)
+            .fold(f64::INFINITY, f64::min);
+        for model in self.models.iter_mut() {
+            model.time_advance(time_delta);
+        }
+        self.clock += time_delta;
+
+        let due: Vec<String> = self
+            .models
+            .iter()
+            .filter(|model| model.until_next_event() <= 0.0)
+            .map(|model| model.id().to_string())
+            .collect();
+        for model_id in due {
+            let (status, messages) = {
+                let model = self
+                    .models
+                    .iter_mut()
+                    .find(|model| model.id() == model_id)
+                    .expect("model present in `due` must still be in `self.models`");
+                let messages = model.events_int(&mut self.uniform_rng)?;
+                (model.status(), messages)
+            };
+            self.emit_record(&model_id, status, messages.clone())?;
+            self.route(&model_id, messages)?;
+
+            if let Some(reason) = self.check_wards(flags) {
+                return Ok(Some(reason));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Runs until a ward stops the simulation, returning why and any flags
+    /// raised along the way.
+    pub fn run(&mut self) -> Result<SimulationResult, SimulationError> {
+        let mut flags = Vec::new();
+        let reason = loop {
+            if let Some(reason) = self.step(&mut flags)? {
+                break reason;
+            }
+        };
+        for output_processor in self.output_processors.iter_mut() {
+            output_processor.finish()?;
+        }
+        Ok(SimulationResult { reason, flags })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ward::MaxEventCountWard;
+    use crate::output_processing::ChannelOutputProcessor;
+
+    /// Fires once, emitting a single message on `"out"`, then goes idle.
+    #[derive(Clone)]
+    struct Source {
+        fired: bool,
+    }
+
+    impl AsModel for Source {
+        fn status(&self) -> String {
+            if self.fired {
+                "fired".to_string()
+            } else {
+                "idle".to_string()
+            }
+        }
+
+        fn events_ext(
+            &mut self,
+            _uniform_rng: &mut UniformRNG,
+            _incoming_message: ModelMessage,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            Ok(Vec::new())
+        }
+
+        fn events_int(
+            &mut self,
+            _uniform_rng: &mut UniformRNG,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            self.fired = true;
+            Ok(vec![ModelMessage::new(
+                "out".to_string(),
+                "hello".to_string(),
+            )])
+        }
+
+        fn time_advance(&mut self, _time_delta: f64) {}
+
+        fn until_next_event(&self) -> f64 {
+            if self.fired {
+                f64::INFINITY
+            } else {
+                0.0
+            }
+        }
+    }
+
+    /// Records the content of whatever it last received via `events_ext`.
+    #[derive(Clone)]
+    struct Sink {
+        received: String,
+    }
+
+    impl AsModel for Sink {
+        fn status(&self) -> String {
+            format!("received `{}`", self.received)
+        }
+
+        fn events_ext(
+            &mut self,
+            _uniform_rng: &mut UniformRNG,
+            incoming_message: ModelMessage,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            self.received = incoming_message.content().to_string();
+            Ok(Vec::new())
+        }
+
+        fn events_int(
+            &mut self,
+            _uniform_rng: &mut UniformRNG,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            Ok(Vec::new())
+        }
+
+        fn time_advance(&mut self, _time_delta: f64) {}
+
+        fn until_next_event(&self) -> f64 {
+            f64::INFINITY
+        }
+    }
+
+    #[test]
+    fn run_stops_after_one_transition_and_routes_the_emitted_message_to_the_sink() {
+        let models = vec![
+            Model::new("source".to_string(), Box::new(Source { fired: false })),
+            Model::new(
+                "sink".to_string(),
+                Box::new(Sink {
+                    received: String::new(),
+                }),
+            ),
+        ];
+        let connectors = vec![Connector::new(
+            "source".to_string(),
+            None,
+            "sink".to_string(),
+            None,
+        )];
+        let mut simulation = Simulation::new(models, connectors, UniformRNG::new(1));
+        simulation.add_ward(Box::new(MaxEventCountWard::new(1)));
+        let (output_processor, receiver) = ChannelOutputProcessor::new();
+        simulation.add_output_processor(Box::new(output_processor));
+
+        let result = simulation.run().unwrap();
+        assert_eq!(
+            result.reason,
+            "event count 1 reached the configured maximum of 1"
+        );
+
+        // One real transition fired (the source's), and routing delivered
+        // its emitted message to the sink - not zero records, which is what
+        // a ward counting its own pre-transition evaluation would produce.
+        let records: Vec<StepRecord> = receiver.try_iter().collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].model_id, "source");
+        assert_eq!(records[0].status, "fired");
+        assert_eq!(records[0].emitted.len(), 1);
+        assert_eq!(records[0].emitted[0].content(), "hello");
+        assert_eq!(records[1].model_id, "sink");
+        assert_eq!(records[1].status, "received `hello`");
+    }
+}
@@ -0,0 +1,189 @@
+use crate::connectors::Connector;
+use crate::models::{AsModel, Model};
+
+/// Whether a rendered graph's edges are directed. DEVS message flow is
+/// directed, so [`to_dot`] defaults to `Directed`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Directed,
+    Undirected,
+}
+
+impl Default for Kind {
+    fn default() -> Self {
+        Kind::Directed
+    }
+}
+
+impl Kind {
+    fn keyword(self) -> &'static str {
+        match self {
+            Kind::Directed => "digraph",
+            Kind::Undirected => "graph",
+        }
+    }
+
+    fn edge_operator(self) -> &'static str {
+        match self {
+            Kind::Directed => "->",
+            Kind::Undirected => "--",
+        }
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Renders `models` and `connectors` as a Graphviz graph, with one node per
+/// model - labeled with its ID, type, and current status - and one edge per
+/// connector from its source model to its target model, labeled with the
+/// connector's ports when it has any. Uses a directed graph by
+/// default, matching how DEVS models exchange messages.
+pub fn to_dot(models: &[Model], connectors: &[Connector]) -> String {
+    to_dot_with_kind(models, connectors, Kind::default())
+}
+
+/// As [`to_dot`], but with an explicit directed/undirected [`Kind`].
+pub fn to_dot_with_kind(models: &[Model], connectors: &[Connector], kind: Kind) -> String {
+    let mut dot = String::new();
+    dot.push_str(kind.keyword());
+    dot.push_str(" {\n");
+    for model in models {
+        dot.push_str(&format!(
+            "    \"{}\" [label=\"{}\\n{}\\n{}\"];\n",
+            escape(model.id()),
+            escape(model.id()),
+            escape(model.get_type()),
+            escape(&model.status())
+        ));
+    }
+    for connector in connectors {
+        let label = match (connector.source_port(), connector.target_port()) {
+            (Some(source_port), Some(target_port)) => {
+                Some(format!("{} -> {}", source_port, target_port))
+            }
+            (Some(port), None) | (None, Some(port)) => Some(port.to_string()),
+            (None, None) => None,
+        }
+        .map(|label| format!(" [label=\"{}\"]", escape(&label)))
+        .unwrap_or_default();
+        dot.push_str(&format!(
+            "    \"{}\" {} \"{}\"{};\n",
+            escape(connector.source_id()),
+            kind.edge_operator(),
+            escape(connector.target_id()),
+            label
+        ));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ModelMessage;
+    use crate::utils::error::SimulationError;
+
+    #[test]
+    fn escape_escapes_backslashes_and_quotes() {
+        assert_eq!(escape(r#"a"b\c"#), r#"a\"b\\c"#);
+        assert_eq!(escape("plain"), "plain");
+    }
+
+    #[test]
+    fn kind_defaults_to_directed() {
+        assert_eq!(Kind::default().keyword(), "digraph");
+        assert_eq!(Kind::default().edge_operator(), "->");
+        assert_eq!(Kind::Undirected.keyword(), "graph");
+        assert_eq!(Kind::Undirected.edge_operator(), "--");
+    }
+
+    #[derive(Clone)]
+    struct Stub {
+        model_type: &'static str,
+        status: &'static str,
+    }
+
+    impl AsModel for Stub {
+        fn get_type(&self) -> &'static str {
+            self.model_type
+        }
+
+        fn status(&self) -> String {
+            self.status.to_string()
+        }
+
+        fn events_ext(
+            &mut self,
+            _uniform_rng: &mut crate::input_modeling::UniformRNG,
+            _incoming_message: ModelMessage,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            Ok(Vec::new())
+        }
+
+        fn events_int(
+            &mut self,
+            _uniform_rng: &mut crate::input_modeling::UniformRNG,
+        ) -> Result<Vec<ModelMessage>, SimulationError> {
+            Ok(Vec::new())
+        }
+
+        fn time_advance(&mut self, _time_delta: f64) {}
+
+        fn until_next_event(&self) -> f64 {
+            f64::INFINITY
+        }
+    }
+
+    #[test]
+    fn to_dot_renders_one_node_per_model_and_one_labeled_edge_per_connector() {
+        let models = vec![
+            Model::new(
+                "a".to_string(),
+                Box::new(Stub {
+                    model_type: "Generator",
+                    status: "Idle",
+                }),
+            ),
+            Model::new(
+                "b".to_string(),
+                Box::new(Stub {
+                    model_type: "Processor",
+                    status: "0 message(s) queued",
+                }),
+            ),
+        ];
+        let connectors = vec![Connector::new(
+            "a".to_string(),
+            Some("out".to_string()),
+            "b".to_string(),
+            Some("in".to_string()),
+        )];
+
+        let dot = to_dot(&models, &connectors);
+
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.contains("\"a\" [label=\"a\\nGenerator\\nIdle\"];\n"));
+        assert!(dot.contains("\"b\" [label=\"b\\nProcessor\\n0 message(s) queued\"];\n"));
+        assert!(dot.contains("\"a\" -> \"b\" [label=\"out -> in\"];\n"));
+    }
+
+    #[test]
+    fn to_dot_with_kind_undirected_uses_the_undirected_keyword_and_operator() {
+        let models = vec![Model::new(
+            "a".to_string(),
+            Box::new(Stub {
+                model_type: "Generator",
+                status: "Idle",
+            }),
+        )];
+        let connectors = vec![Connector::new("a".to_string(), None, "a".to_string(), None)];
+
+        let dot = to_dot_with_kind(&models, &connectors, Kind::Undirected);
+
+        assert!(dot.starts_with("graph {\n"));
+        assert!(dot.contains("\"a\" -- \"a\";\n"));
+    }
+}
@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+/// The error type returned by fallible simulation operations - model
+/// (de)serialization, message payload conversion, and the transition
+/// functions themselves.
+#[derive(Error, Debug)]
+pub enum SimulationError {
+    #[error("model (de)serialization error: {0}")]
+    SerializationError(#[from] serde_yaml::Error),
+    #[error("payload conversion error: {0}")]
+    ConversionError(String),
+    #[error("output processing error: {0}")]
+    OutputProcessingError(String),
+}
+
+impl From<std::io::Error> for SimulationError {
+    fn from(err: std::io::Error) -> Self {
+        SimulationError::OutputProcessingError(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for SimulationError {
+    fn from(err: serde_json::Error) -> Self {
+        SimulationError::OutputProcessingError(err.to_string())
+    }
+}
+
+impl From<csv::Error> for SimulationError {
+    fn from(err: csv::Error) -> Self {
+        SimulationError::OutputProcessingError(err.to_string())
+    }
+}
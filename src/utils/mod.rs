@@ -0,0 +1,3 @@
+pub mod dot;
+pub mod error;
+pub(crate) mod routing;
@@ -0,0 +1,13 @@
+use crate::connectors::Connector;
+
+/// Returns the ids of every model connected downstream of `source_id`, i.e.
+/// every connector whose `source_id()` matches. Shared by `Simulation` and
+/// `RealtimeRunner` so the two drivers look up connectors the same way
+/// instead of maintaining independent copies of this loop.
+pub(crate) fn targets_for(connectors: &[Connector], source_id: &str) -> Vec<String> {
+    connectors
+        .iter()
+        .filter(|connector| connector.source_id() == source_id)
+        .map(|connector| connector.target_id().to_string())
+        .collect()
+}